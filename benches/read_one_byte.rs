@@ -0,0 +1,35 @@
+use std::hint::black_box;
+
+use bytecursor::ByteCursor;
+use criterion::{
+  criterion_group,
+  criterion_main,
+  BatchSize,
+  Criterion,
+};
+
+// Reads a `Bytecursor` one byte at a time, the tightest loop the
+// `Buffer`/`consume_with` refactor targets: each iteration should cost a
+// single bounds check rather than `fill_buf`'s check plus a second one in
+// `read`. The setup clone is excluded from the timed portion via
+// `iter_batched`, so only the read loop itself is measured.
+fn read_one_byte(c: &mut Criterion) {
+  let data = vec![0u8; 1 << 16];
+  c.bench_function("read_one_byte", |b| {
+    b.iter_batched(
+      || ByteCursor::new(data.clone()),
+      |mut cursor| {
+        let mut byte = [0u8; 1];
+        let mut total = 0u64;
+        while cursor.read(&mut byte) == 1 {
+          total += u64::from(byte[0]);
+        }
+        (black_box(total), cursor)
+      },
+      BatchSize::SmallInput,
+    );
+  });
+}
+
+criterion_group!(benches, read_one_byte);
+criterion_main!(benches);
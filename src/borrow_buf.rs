@@ -0,0 +1,100 @@
+//! A `BorrowedBuf`/`BorrowedCursor`-style pair, modeled after the std
+//! `ReadBuf` redesign, that lets a reader fill a caller-provided
+//! uninitialized region without forcing it to be zeroed first.
+
+use core::mem::{
+  self,
+  MaybeUninit,
+};
+
+/// A mutable, possibly-uninitialized buffer that's progressively filled.
+/// Tracks how many bytes have been written (`filled`) and how many are
+/// known to already be initialized (`init`), so a reader can hand bytes
+/// back without requiring the whole buffer to be zeroed up front.
+pub struct BorrowBuf<'data> {
+  buf: &'data mut [MaybeUninit<u8>],
+  filled: usize,
+  init: usize,
+}
+
+impl<'data> BorrowBuf<'data> {
+  /// Creates a new `BorrowBuf` over `buf`, assuming none of it is
+  /// initialized yet.
+  #[must_use]
+  pub fn new(buf: &'data mut [MaybeUninit<u8>]) -> Self {
+    Self { buf, filled: 0, init: 0 }
+  }
+
+  /// Returns the total capacity of the underlying buffer.
+  #[must_use]
+  pub fn capacity(&self) -> usize { self.buf.len() }
+
+  /// Returns the number of bytes that have been filled so far.
+  #[must_use]
+  pub fn len(&self) -> usize { self.filled }
+
+  /// Returns `true` if no bytes have been filled yet.
+  #[must_use]
+  pub fn is_empty(&self) -> bool { self.filled == 0 }
+
+  /// Returns the number of bytes known to already be initialized,
+  /// including any filled by a previous use of the underlying buffer.
+  #[must_use]
+  pub fn init_len(&self) -> usize { self.init }
+
+  /// Returns the filled portion of the buffer as an initialized slice.
+  #[must_use]
+  pub fn filled(&self) -> &[u8] {
+    let filled = &self.buf[..self.filled];
+    // Safety: `BorrowCursor::put_slice` only ever advances `filled` after
+    // writing through and marking initialized the bytes it copies, so
+    // `[0, self.filled)` is always initialized here.
+    unsafe { &*(filled as *const [MaybeUninit<u8>] as *const [u8]) }
+  }
+
+  /// Returns a cursor over the unfilled portion of the buffer, for
+  /// writing into.
+  pub fn unfilled<'this>(&'this mut self) -> BorrowCursor<'this> {
+    BorrowCursor {
+      // Safety: a `BorrowCursor` never replaces `buf`, only writes through
+      // `put_slice`, so shortening the buffer's lifetime to the cursor's
+      // borrow here is sound.
+      buf: unsafe {
+        mem::transmute::<&'this mut BorrowBuf<'data>, &'this mut BorrowBuf<'this>>(self)
+      },
+    }
+  }
+}
+
+/// A write-only cursor over the unfilled, possibly-uninitialized tail of a
+/// `BorrowBuf`.
+pub struct BorrowCursor<'a> {
+  buf: &'a mut BorrowBuf<'a>,
+}
+
+impl<'a> BorrowCursor<'a> {
+  /// Returns the number of bytes still unfilled in the underlying buffer.
+  #[must_use]
+  pub fn remaining(&self) -> usize { self.buf.capacity() - self.buf.filled }
+
+  /// Copies `buf` into the cursor's current position, advancing both the
+  /// underlying `BorrowBuf`'s `filled` and `init` counts by `buf.len()`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `buf` is longer than `self.remaining()`.
+  pub fn put_slice(&mut self, buf: &[u8]) {
+    assert!(buf.len() <= self.remaining(), "buf.len() must be <= remaining()");
+    let start = self.buf.filled;
+    let end = start + buf.len();
+    // Safety: `[start, end)` lies entirely within the unfilled region
+    // just checked against `remaining()`, and `MaybeUninit<u8>` shares
+    // `u8`'s layout, so writing through a raw `u8` pointer is sound.
+    unsafe {
+      let dst = self.buf.buf[start..end].as_mut_ptr().cast::<u8>();
+      core::ptr::copy_nonoverlapping(buf.as_ptr(), dst, buf.len());
+    }
+    self.buf.filled = end;
+    self.buf.init = self.buf.init.max(end);
+  }
+}
@@ -0,0 +1,163 @@
+use core::cmp;
+
+use alloc::{
+  borrow::ToOwned,
+  string::String,
+  vec::Vec,
+};
+
+use crate::bytecursor::SeekFrom;
+
+/// A cursor over an ordered collection of byte segments that reads,
+/// seeks, and fills buffers without ever concatenating the segments
+/// into a single contiguous `Vec`.
+#[derive(Clone, Debug)]
+pub struct ByteCursorList {
+  segments: Vec<Vec<u8>>,
+  // offsets[i] is the cumulative length of segments[0..=i], so it
+  // doubles as the exclusive end position of segment i.
+  offsets: Vec<u64>,
+  pos: u64,
+}
+
+impl ByteCursorList {
+  /// Creates a new `ByteCursorList` from an ordered collection of byte
+  /// segments. Sets the position to 0 initially.
+  #[must_use]
+  pub fn new(segments: Vec<Vec<u8>>) -> Self {
+    let mut total = 0u64;
+    let offsets =
+      segments
+        .iter()
+        .map(|seg| {
+          total += seg.len() as u64;
+          total
+        })
+        .collect();
+    Self { segments, offsets, pos: 0 }
+  }
+
+  /// Consumes the `ByteCursorList`, returning the inner segments.
+  #[must_use]
+  pub fn into_segments(self) -> Vec<Vec<u8>> { self.segments }
+
+  /// Returns the number of segments in the `ByteCursorList`.
+  #[must_use]
+  pub fn num_segments(&self) -> usize { self.segments.len() }
+
+  /// Returns the total length, in bytes, of all segments combined.
+  #[must_use]
+  pub fn len(&self) -> u64 { self.offsets.last().copied().unwrap_or(0) }
+
+  /// Returns `true` if the `ByteCursorList` has no segments, or only
+  /// empty ones.
+  #[must_use]
+  pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+  /// Returns the current position of the `ByteCursorList`.
+  #[must_use]
+  pub const fn position(&self) -> u64 { self.pos }
+
+  /// Sets the position of the `ByteCursorList` to `pos`.
+  pub fn set_position(&mut self, pos: u64) { self.pos = pos }
+
+  // Binary-searches the cumulative-length index for the segment and
+  // in-segment offset that `pos` falls into.
+  fn locate(&self, pos: u64) -> (usize, usize) {
+    if pos >= self.len() {
+      return (self.segments.len(), 0);
+    }
+    let (mut idx, start) = match self.offsets.binary_search(&pos) {
+      // `pos` lands exactly on the boundary between two segments, i.e.
+      // the start of the next one. `offsets` can repeat across empty
+      // segments, so `binary_search` may land on any of the matching
+      // indices here.
+      Ok(i) => (i + 1, self.offsets[i]),
+      Err(i) => (i, if i == 0 { 0 } else { self.offsets[i - 1] }),
+    };
+    // An empty segment doesn't advance `offsets`, so `start` is still the
+    // position it and every following empty segment begin at. Skip over
+    // them: since `pos < self.len()`, a non-empty segment starting at
+    // `start` is guaranteed to exist.
+    while self.segments[idx].is_empty() {
+      idx += 1;
+    }
+    (idx, (pos - start) as usize)
+  }
+
+  /// Returns a byte slice containing the contiguous bytes remaining in
+  /// the segment the `ByteCursorList`'s current position falls into.
+  /// Unlike a gathered buffer's full remaining length, this does not
+  /// span into later segments.
+  pub fn fill_buf(&mut self) -> &[u8] {
+    let (seg_idx, offset) = self.locate(self.pos);
+    match self.segments.get(seg_idx) {
+      Some(seg) => &seg[offset..],
+      None => &[],
+    }
+  }
+
+  /// Reads up to `buf.len()` bytes into `buf` from the `ByteCursorList`,
+  /// advancing its position and copying across segment boundaries as
+  /// needed. It returns the number of bytes actually read.
+  pub fn read(&mut self, buf: &mut [u8]) -> usize {
+    let mut total = 0;
+    while total < buf.len() {
+      let chunk = self.fill_buf();
+      if chunk.is_empty() {
+        break;
+      }
+      let amt = cmp::min(buf.len() - total, chunk.len());
+      buf[total..total + amt].copy_from_slice(&chunk[..amt]);
+      self.pos += amt as u64;
+      total += amt;
+    }
+    total
+  }
+
+  /// Reads exactly `buf.len()` bytes into `buf`, throwing an error if
+  /// that number of bytes was not able to be read.
+  /// # Errors
+  ///
+  /// Will return `Err` if the buffer is longer than the available bytes to read
+  pub fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), String> {
+    let n = buf.len();
+    if self.read(buf) != n {
+      return Err("failed to fill whole buffer".to_owned());
+    }
+    Ok(())
+  }
+
+  /// Seeks to the position referenced by `style`, returning the new position
+  /// of the `ByteCursorList` and throwing an error if the new position would
+  /// be invalid.
+  /// # Errors
+  ///
+  /// Will return `Err` if one tries to seek to a negative or overflowing
+  /// position
+  pub fn seek(&mut self, style: &SeekFrom) -> Result<u64, String> {
+    let (base_pos, offset) = match style {
+      SeekFrom::Start(n) => {
+        self.pos = *n;
+        return Ok(*n);
+      }
+      SeekFrom::End(n) => (self.len(), n),
+      SeekFrom::Current(n) => (self.pos, n),
+    };
+    let new_pos = if *offset >= 0 {
+      base_pos.checked_add(*offset as u64) // may lose sign
+    }
+    else {
+      base_pos.checked_sub((offset.wrapping_neg()) as u64) // may lose sign
+    };
+    match new_pos {
+      Some(n) => {
+        self.pos = n;
+        Ok(self.pos)
+      }
+      None => {
+        Err("invalid seek to a negative or overflowing position".to_owned())
+      }
+    }
+  }
+}
@@ -9,12 +9,48 @@ use alloc::{
   vec::Vec,
 };
 
+use crate::{
+  borrow_buf::BorrowCursor,
+  io_slice::{IoSlice, IoSliceMut},
+};
+
 pub enum SeekFrom {
   Start(u64),
   End(i64),
   Current(i64),
 }
 
+// Caches the remaining-slice bounds for a `Bytecursor` so that the read
+// paths below do one bounds check (in `consume_with`) instead of computing
+// the remaining slice via `fill_buf` and then re-checking it themselves.
+struct Buffer<'a> {
+  remaining: &'a [u8],
+  pos: &'a mut u64,
+}
+
+impl<'a> Buffer<'a> {
+  fn new(inner: &'a [u8], pos: &'a mut u64) -> Self {
+    let start = cmp::min(*pos, inner.len() as u64) as usize;
+    Self { remaining: &inner[start..], pos }
+  }
+
+  fn remaining(&self) -> &[u8] { self.remaining }
+
+  // Hands the first `amt` bytes of the remaining slice to `f` after a
+  // single bounds check, then advances `pos` by `amt`.
+  fn consume_with<F, T>(&mut self, amt: usize, f: F) -> Result<T, String>
+  where F: FnOnce(&[u8]) -> T {
+    if amt > self.remaining.len() {
+      return Err("failed to fill whole buffer".to_owned());
+    }
+    let (a, b) = self.remaining.split_at(amt);
+    let result = f(a);
+    self.remaining = b;
+    *self.pos += amt as u64;
+    Ok(result)
+  }
+}
+
 #[derive(Clone, Debug)]
 pub struct ByteCursor {
   inner: Vec<u8>,
@@ -49,17 +85,18 @@ impl ByteCursor {
   /// the `Bytecursor`'s position. It returns the number of bytes
   /// actually read.
   pub fn read(&mut self, buf: &mut [u8]) -> usize {
-    let from = &mut self.fill_buf();
-    let amt = cmp::min(buf.len(), from.len());
-    let (a, b) = from.split_at(amt);
-    if amt == 1 {
-      buf[0] = a[0];
-    }
-    else {
-      buf[..amt].copy_from_slice(a);
-    }
-    *from = b;
-    self.pos += amt as u64;
+    let mut buffer = Buffer::new(&self.inner, &mut self.pos);
+    let amt = cmp::min(buf.len(), buffer.remaining().len());
+    buffer
+      .consume_with(amt, |a| {
+        if amt == 1 {
+          buf[0] = a[0];
+        }
+        else {
+          buf[..amt].copy_from_slice(a);
+        }
+      })
+      .expect("amt was clamped to the remaining slice's length");
     amt
   }
 
@@ -70,22 +107,14 @@ impl ByteCursor {
   /// Will return `Err` if the buffer is longer than the available bytes to read
   pub fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), String> {
     let n = buf.len();
-    let from = &mut self.fill_buf();
-    if buf.len() > from.len() {
-      return Err("failed to fill whole buffer".to_owned());
-    }
-    let (a, b) = from.split_at(buf.len());
-
-    if buf.len() == 1 {
-      buf[0] = a[0];
-    }
-    else {
-      buf.copy_from_slice(a);
-    }
-
-    *from = b;
-    self.pos += n as u64;
-    Ok(())
+    Buffer::new(&self.inner, &mut self.pos).consume_with(n, |a| {
+      if n == 1 {
+        buf[0] = a[0];
+      }
+      else {
+        buf.copy_from_slice(a);
+      }
+    })
   }
 
   /// Returns a byte slice containing all remaining bytes
@@ -131,6 +160,43 @@ impl ByteCursor {
     }
   }
 
+  // `std::io::Seek` already provides `rewind`/`stream_position`/
+  // `stream_len`/`seek_relative` as default methods backed by `seek`, so
+  // once the `std` feature's `impl Seek for Bytecursor` (in `std_io`) is in
+  // scope those take over instead. Defining inherent methods of the same
+  // name unconditionally would shadow the trait's on a concrete
+  // `Bytecursor`, silently swapping their `io::Result<_>` return types for
+  // these infallible/`String`-erroring ones. Only provide the inherent
+  // versions for the `no_std` API, where the trait isn't available.
+
+  /// Rewinds the `Bytecursor` to position 0, equivalent to
+  /// `seek(&SeekFrom::Start(0))` but infallible.
+  #[cfg(not(feature = "std"))]
+  pub fn rewind(&mut self) { self.pos = 0; }
+
+  /// Returns the current position of the `Bytecursor`, without moving it.
+  /// Equivalent to `position`, provided to mirror `std::io::Seek`.
+  #[cfg(not(feature = "std"))]
+  #[must_use]
+  pub const fn stream_position(&self) -> u64 { self.pos }
+
+  /// Returns the total length, in bytes, of the `Bytecursor`'s inner bytes.
+  #[cfg(not(feature = "std"))]
+  #[must_use]
+  pub fn stream_len(&mut self) -> u64 { self.inner.len() as u64 }
+
+  /// Adjusts the position of the `Bytecursor` by a signed `offset` relative
+  /// to the current position, equivalent to
+  /// `seek(&SeekFrom::Current(offset))` but without returning the new
+  /// position. # Errors
+  ///
+  /// Will return `Err` if the adjusted position would be negative or
+  /// overflowing
+  #[cfg(not(feature = "std"))]
+  pub fn seek_relative(&mut self, offset: i64) -> Result<(), String> {
+    self.seek(&SeekFrom::Current(offset)).map(|_| ())
+  }
+
   /// Writes `buf.len()` bytes into `buf`. Returns the number of bytes actually
   /// read if successful, and throws an error if there aren't enough bytes to
   /// read. # Errors
@@ -156,6 +222,60 @@ impl ByteCursor {
     Ok(buf.len())
   }
 
+  /// Fills the supplied buffers in order from the bytes remaining after the
+  /// `Bytecursor`'s current position, stopping once either the buffers or
+  /// the remaining bytes are exhausted. Returns the total number of bytes
+  /// copied, and advances the position by that amount.
+  pub fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> usize {
+    let mut buffer = Buffer::new(&self.inner, &mut self.pos);
+    let mut total = 0;
+    for buf in bufs.iter_mut() {
+      if buffer.remaining().is_empty() {
+        break;
+      }
+      let amt = cmp::min(buf.len(), buffer.remaining().len());
+      if amt == 0 {
+        continue;
+      }
+      buffer
+        .consume_with(amt, |a| buf.as_mut_slice()[..amt].copy_from_slice(a))
+        .expect("amt was clamped to the remaining slice's length");
+      total += amt;
+    }
+    total
+  }
+
+  /// Writes each of the supplied buffers in sequence into the `Bytecursor`,
+  /// using the same growth behavior as `write`. Returns the total number of
+  /// bytes written.
+  /// # Errors
+  ///
+  /// Will return `Err` if the cursor position exceeds maximum possible vector
+  /// length
+  pub fn write_vectored(&mut self, bufs: &[IoSlice]) -> Result<usize, String> {
+    let mut total = 0;
+    for buf in bufs {
+      total += self.write(buf.as_slice())?;
+    }
+    Ok(total)
+  }
+
+  /// Fills `cursor` with up to `cursor.remaining()` bytes from the bytes
+  /// remaining after the `Bytecursor`'s current position, without requiring
+  /// `cursor`'s underlying buffer to be zeroed first. Advances the position
+  /// by the number of bytes copied.
+  ///
+  /// Because a `Bytecursor` always reads out of an already-initialized
+  /// `Vec<u8>`, every byte copied in this way can be soundly marked
+  /// initialized in `cursor`.
+  pub fn read_buf(&mut self, mut cursor: BorrowCursor) {
+    let mut buffer = Buffer::new(&self.inner, &mut self.pos);
+    let amt = cmp::min(cursor.remaining(), buffer.remaining().len());
+    buffer
+      .consume_with(amt, |a| cursor.put_slice(a))
+      .expect("amt was clamped to the remaining slice's length");
+  }
+
   /// Writes all of `buf` to the `Bytecursor` until `buf` is empty.
   /// # Errors
   ///
@@ -0,0 +1,57 @@
+//! Lightweight, `no_std`-friendly stand-ins for `std::io::{IoSlice,
+//! IoSliceMut}`, used by `Bytecursor`'s vectored read/write methods.
+
+/// A wrapper around a byte slice for vectored writes, mirroring
+/// `std::io::IoSlice` without the platform-specific ABI guarantees that
+/// type carries.
+#[derive(Clone, Copy, Debug)]
+pub struct IoSlice<'a> {
+  buf: &'a [u8],
+}
+
+impl<'a> IoSlice<'a> {
+  /// Creates a new `IoSlice` wrapping `buf`.
+  #[must_use]
+  pub const fn new(buf: &'a [u8]) -> Self { Self { buf } }
+
+  /// Returns the wrapped byte slice.
+  #[must_use]
+  pub const fn as_slice(&self) -> &[u8] { self.buf }
+
+  /// Returns the length of the wrapped byte slice.
+  #[must_use]
+  pub const fn len(&self) -> usize { self.buf.len() }
+
+  /// Returns `true` if the wrapped byte slice has a length of 0.
+  #[must_use]
+  pub const fn is_empty(&self) -> bool { self.buf.is_empty() }
+}
+
+/// A wrapper around a mutable byte slice for vectored reads, mirroring
+/// `std::io::IoSliceMut` without the platform-specific ABI guarantees that
+/// type carries.
+#[derive(Debug)]
+pub struct IoSliceMut<'a> {
+  buf: &'a mut [u8],
+}
+
+impl<'a> IoSliceMut<'a> {
+  /// Creates a new `IoSliceMut` wrapping `buf`.
+  #[must_use]
+  pub fn new(buf: &'a mut [u8]) -> Self { Self { buf } }
+
+  /// Returns the wrapped byte slice.
+  #[must_use]
+  pub fn as_slice(&self) -> &[u8] { self.buf }
+
+  /// Returns the wrapped byte slice, mutably.
+  pub fn as_mut_slice(&mut self) -> &mut [u8] { self.buf }
+
+  /// Returns the length of the wrapped byte slice.
+  #[must_use]
+  pub fn len(&self) -> usize { self.buf.len() }
+
+  /// Returns `true` if the wrapped byte slice has a length of 0.
+  #[must_use]
+  pub fn is_empty(&self) -> bool { self.buf.is_empty() }
+}
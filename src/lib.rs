@@ -0,0 +1,15 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod borrow_buf;
+mod byte_cursor_list;
+mod bytecursor;
+mod io_slice;
+#[cfg(feature = "std")]
+mod std_io;
+
+pub use borrow_buf::{BorrowBuf, BorrowCursor};
+pub use byte_cursor_list::ByteCursorList;
+pub use bytecursor::{ByteCursor, SeekFrom};
+pub use io_slice::{IoSlice, IoSliceMut};
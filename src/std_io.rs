@@ -0,0 +1,74 @@
+//! `std::io` trait implementations for `Bytecursor`, enabled by the
+//! non-default `std` feature so a `Bytecursor` can be dropped into generic
+//! `R: Read + Seek` code (serde readers, decompressors, `std::io::copy`)
+//! without callers hand-rolling adapters. The `no_std` inherent API is
+//! unaffected either way; these impls just delegate to it.
+
+use alloc::string::String;
+use std::io::{
+  self,
+  BufRead,
+  ErrorKind,
+  Read,
+  Seek,
+  Write,
+};
+
+use crate::{
+  bytecursor::SeekFrom as ByteCursorSeekFrom,
+  ByteCursor,
+};
+
+// The inherent API only ever fails with "not enough bytes remaining" or
+// "seek/position would under- or overflow"; map the former to the closest
+// matching `ErrorKind` and fall back to `InvalidInput` for the rest.
+fn to_io_error(message: String) -> io::Error {
+  let kind = if message.contains("fill whole buffer") {
+    ErrorKind::UnexpectedEof
+  }
+  else {
+    ErrorKind::InvalidInput
+  };
+  io::Error::new(kind, message)
+}
+
+impl Read for ByteCursor {
+  fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    Ok(ByteCursor::read(self, buf))
+  }
+
+  fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+    ByteCursor::read_exact(self, buf).map_err(to_io_error)
+  }
+}
+
+impl Write for ByteCursor {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    ByteCursor::write(self, buf).map_err(to_io_error)
+  }
+
+  fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+    ByteCursor::write_all(self, buf).map_err(to_io_error)
+  }
+
+  fn flush(&mut self) -> io::Result<()> { Ok(()) }
+}
+
+impl Seek for ByteCursor {
+  fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+    let pos = match pos {
+      io::SeekFrom::Start(n) => ByteCursorSeekFrom::Start(n),
+      io::SeekFrom::End(n) => ByteCursorSeekFrom::End(n),
+      io::SeekFrom::Current(n) => ByteCursorSeekFrom::Current(n),
+    };
+    ByteCursor::seek(self, &pos).map_err(to_io_error)
+  }
+}
+
+impl BufRead for ByteCursor {
+  fn fill_buf(&mut self) -> io::Result<&[u8]> { Ok(ByteCursor::fill_buf(self)) }
+
+  fn consume(&mut self, amt: usize) {
+    self.set_position(self.position() + amt as u64);
+  }
+}